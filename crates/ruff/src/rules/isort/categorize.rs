@@ -0,0 +1,228 @@
+//! Categorization of imports into sections (e.g. `future`, `standard-library`, `first-party`),
+//! and the compiled module-name patterns ([`ModulePattern`]) used to recognize them.
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use ruff_cache::CacheKey;
+use ruff_macros::CacheKey as CacheKeyDerive;
+
+use crate::settings::types::IdentifierPattern;
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    CacheKeyDerive,
+    EnumIter,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ImportType {
+    Future,
+    StandardLibrary,
+    ThirdParty,
+    FirstParty,
+    LocalFolder,
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, CacheKeyDerive,
+)]
+#[serde(untagged)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ImportSection {
+    Known(ImportType),
+    UserDefined(String),
+}
+
+/// A compiled module-name pattern: either glob-based (the default, via
+/// [`IdentifierPattern`]) or regex-based, when `treat-sections-as-regex` is enabled.
+#[derive(Debug, Clone)]
+pub enum ModulePattern {
+    Glob(IdentifierPattern),
+    Regex(Box<regex::Regex>),
+}
+
+impl ModulePattern {
+    pub fn glob(source: &str) -> Result<Self, glob::PatternError> {
+        IdentifierPattern::new(source).map(ModulePattern::Glob)
+    }
+
+    pub fn regex(source: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(source).map(|regex| ModulePattern::Regex(Box::new(regex)))
+    }
+
+    /// Returns `true` if `module` matches this pattern.
+    pub fn is_match(&self, module: &str) -> bool {
+        match self {
+            ModulePattern::Glob(pattern) => pattern.matches(module),
+            ModulePattern::Regex(regex) => regex.is_match(module),
+        }
+    }
+
+    /// The original source text of the pattern (the glob or regex as written by the user).
+    fn source(&self) -> &str {
+        match self {
+            ModulePattern::Glob(pattern) => pattern.as_str(),
+            ModulePattern::Regex(regex) => regex.as_str(),
+        }
+    }
+}
+
+impl CacheKey for ModulePattern {
+    fn cache_key(&self, state: &mut ruff_cache::CacheKeyHasher) {
+        self.source().cache_key(state);
+    }
+}
+
+/// The set of module-name patterns used to categorize imports into sections.
+#[derive(Debug, Clone, Default)]
+pub struct KnownModules {
+    known_first_party: Vec<ModulePattern>,
+    known_third_party: Vec<ModulePattern>,
+    known_local_folder: Vec<ModulePattern>,
+    extra_standard_library: Vec<ModulePattern>,
+    sections: FxHashMap<String, Vec<ModulePattern>>,
+}
+
+impl KnownModules {
+    pub fn new(
+        known_first_party: Vec<ModulePattern>,
+        known_third_party: Vec<ModulePattern>,
+        known_local_folder: Vec<ModulePattern>,
+        extra_standard_library: Vec<ModulePattern>,
+        sections: FxHashMap<String, Vec<ModulePattern>>,
+    ) -> Self {
+        Self {
+            known_first_party,
+            known_third_party,
+            known_local_folder,
+            extra_standard_library,
+            sections,
+        }
+    }
+
+    /// Returns the configured module patterns (rendered back to their source text) for a given
+    /// built-in [`ImportType`], for round-tripping back into [`super::settings::Options`].
+    pub fn modules_for_known_type(&self, import_type: ImportType) -> impl Iterator<Item = &str> {
+        let modules: &[ModulePattern] = match import_type {
+            ImportType::FirstParty => &self.known_first_party,
+            ImportType::ThirdParty => &self.known_third_party,
+            ImportType::LocalFolder => &self.known_local_folder,
+            ImportType::StandardLibrary => &self.extra_standard_library,
+            ImportType::Future => &[],
+        };
+        modules.iter().map(ModulePattern::source)
+    }
+
+    /// Returns the user-defined sections (name, module patterns), for round-tripping back into
+    /// [`super::settings::Options`].
+    pub fn user_defined(&self) -> Vec<(&str, Vec<&str>)> {
+        self.sections
+            .iter()
+            .map(|(section, modules)| {
+                (
+                    section.as_str(),
+                    modules.iter().map(ModulePattern::source).collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// Categorize `module` into a known or user-defined section, if any pattern matches.
+    /// User-defined sections are checked first, so that they can override the built-ins.
+    pub fn categorize(&self, module: &str) -> Option<ImportSection> {
+        for (section, patterns) in &self.sections {
+            if patterns.iter().any(|pattern| pattern.is_match(module)) {
+                return Some(ImportSection::UserDefined(section.clone()));
+            }
+        }
+        if self.known_first_party.iter().any(|p| p.is_match(module)) {
+            return Some(ImportSection::Known(ImportType::FirstParty));
+        }
+        if self.known_third_party.iter().any(|p| p.is_match(module)) {
+            return Some(ImportSection::Known(ImportType::ThirdParty));
+        }
+        if self.known_local_folder.iter().any(|p| p.is_match(module)) {
+            return Some(ImportSection::Known(ImportType::LocalFolder));
+        }
+        if self
+            .extra_standard_library
+            .iter()
+            .any(|p| p.is_match(module))
+        {
+            return Some(ImportSection::Known(ImportType::StandardLibrary));
+        }
+        None
+    }
+}
+
+impl CacheKey for KnownModules {
+    fn cache_key(&self, state: &mut ruff_cache::CacheKeyHasher) {
+        self.known_first_party.cache_key(state);
+        self.known_third_party.cache_key(state);
+        self.known_local_folder.cache_key(state);
+        self.extra_standard_library.cache_key(state);
+        let mut sections: Vec<_> = self.sections.iter().collect();
+        sections.sort_by_key(|(name, _)| name.as_str());
+        sections.cache_key(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_pattern_matches() {
+        let pattern = ModulePattern::glob("src.*").unwrap();
+        assert!(pattern.is_match("src.utils"));
+        assert!(!pattern.is_match("other.utils"));
+    }
+
+    #[test]
+    fn regex_pattern_matches() {
+        let pattern = ModulePattern::regex(r"^foo\d+$").unwrap();
+        assert!(pattern.is_match("foo123"));
+        assert!(!pattern.is_match("foo"));
+        assert!(!pattern.is_match("bar123"));
+    }
+
+    #[test]
+    fn regex_syntax_is_literal_as_glob_but_matches_as_regex() {
+        // `^foo\d+$` compiles as a glob, but `glob::Pattern` treats `^`, `\d`, `+` and `$`
+        // literally, so only the regex matches `foo` followed by digits - this is exactly the
+        // case `treat-sections-as-regex` exists to support.
+        assert!(!ModulePattern::glob(r"^foo\d+$").unwrap().is_match("foo123"));
+        assert!(ModulePattern::regex(r"^foo\d+$").unwrap().is_match("foo123"));
+    }
+
+    #[test]
+    fn user_defined_sections_take_priority() {
+        let mut sections = FxHashMap::default();
+        sections.insert(
+            "django".to_string(),
+            vec![ModulePattern::glob("django.*").unwrap()],
+        );
+        let known_modules = KnownModules::new(
+            vec![ModulePattern::glob("django.*").unwrap()],
+            vec![],
+            vec![],
+            vec![],
+            sections,
+        );
+        assert_eq!(
+            known_modules.categorize("django.db"),
+            Some(ImportSection::UserDefined("django".to_string()))
+        );
+    }
+}
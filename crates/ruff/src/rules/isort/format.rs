@@ -0,0 +1,59 @@
+//! Rendering decisions for a sorted block of imports.
+
+use super::categorize::ImportSection;
+use super::settings::Settings;
+
+/// Returns `true` if a `from` import with `member_count` imported members should be
+/// wrapped one member per line, regardless of line length, per `force-grid-wrap`.
+///
+/// `force-grid-wrap = 0` (the default) disables this behavior, in which case wrapping
+/// is driven by line length alone.
+pub fn should_force_grid_wrap(member_count: usize, settings: &Settings) -> bool {
+    settings.force_grid_wrap != 0 && member_count >= settings.force_grid_wrap
+}
+
+/// Returns the heading comment (if any) that should be emitted immediately above
+/// `section`'s import block, per `import-headings`.
+pub fn section_heading<'a>(section: &ImportSection, settings: &'a Settings) -> Option<&'a str> {
+    settings.import_headings.get(section).map(String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::isort::ImportType;
+
+    #[test]
+    fn grid_wrap_disabled_by_default() {
+        let settings = Settings::default();
+        assert!(!should_force_grid_wrap(10, &settings));
+    }
+
+    #[test]
+    fn grid_wrap_applies_at_or_above_threshold() {
+        let settings = Settings {
+            force_grid_wrap: 2,
+            ..Settings::default()
+        };
+        assert!(!should_force_grid_wrap(1, &settings));
+        assert!(should_force_grid_wrap(2, &settings));
+        assert!(should_force_grid_wrap(3, &settings));
+    }
+
+    #[test]
+    fn section_heading_returns_configured_text() {
+        let section = ImportSection::Known(ImportType::FirstParty);
+        let mut settings = Settings::default();
+        settings
+            .import_headings
+            .insert(section.clone(), "Local imports".to_string());
+        assert_eq!(section_heading(&section, &settings), Some("Local imports"));
+    }
+
+    #[test]
+    fn section_heading_is_none_when_unconfigured() {
+        let section = ImportSection::Known(ImportType::ThirdParty);
+        let settings = Settings::default();
+        assert_eq!(section_heading(&section, &settings), None);
+    }
+}
@@ -11,9 +11,8 @@ use strum::IntoEnumIterator;
 
 use ruff_macros::{CacheKey, CombineOptions, ConfigurationOptions};
 
-use crate::rules::isort::categorize::KnownModules;
+use crate::rules::isort::categorize::{KnownModules, ModulePattern};
 use crate::rules::isort::ImportType;
-use crate::settings::types::IdentifierPattern;
 use crate::warn_user_once;
 
 use super::categorize::ImportSection;
@@ -36,6 +35,55 @@ impl Default for RelativeImportsOrder {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, CacheKey)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum SortOrder {
+    /// Sort imports alphabetically (the default behavior).
+    Alphabetical,
+    /// Preserve the order in which imports first appear in the source,
+    /// within each section, instead of re-sorting them alphabetically.
+    Appearance,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        Self::Alphabetical
+    }
+}
+
+/// The module membership (and, optionally, ordering policy override) for a
+/// single entry in `sections`. Accepts either a bare list of modules (in
+/// which case the global `sort-order` applies), or a table specifying both
+/// the modules and a per-section `order`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, CacheKey)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case", untagged)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum SectionConfig {
+    Modules(Vec<String>),
+    WithOrder {
+        #[serde(default)]
+        modules: Vec<String>,
+        order: Option<SortOrder>,
+    },
+}
+
+impl SectionConfig {
+    fn modules(&self) -> &[String] {
+        match self {
+            SectionConfig::Modules(modules) => modules,
+            SectionConfig::WithOrder { modules, .. } => modules,
+        }
+    }
+
+    fn order(&self) -> Option<SortOrder> {
+        match self {
+            SectionConfig::Modules(_) => None,
+            SectionConfig::WithOrder { order, .. } => *order,
+        }
+    }
+}
+
 #[derive(
     Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, CombineOptions,
 )]
@@ -71,6 +119,18 @@ pub struct Options {
     /// enabled, every aliased `import from` will be given its own line, in
     /// which case, wrapping is not necessary.
     pub force_wrap_aliases: Option<bool>,
+    #[option(
+        default = r#"0"#,
+        value_type = "int",
+        example = r#"force-grid-wrap = 2"#
+    )]
+    /// Force `from` imports with multiple members to always wrap, one member
+    /// per line, whenever the number of imported members is greater than or
+    /// equal to this value, regardless of the line length.
+    ///
+    /// Set to `0` (the default) to disable this behavior, in which case
+    /// wrapping is only driven by line length.
+    pub force_grid_wrap: Option<usize>,
     #[option(
         default = r#"false"#,
         value_type = "bool",
@@ -130,6 +190,16 @@ pub struct Options {
     /// imports (like `from itertools import groupby`). Instead, sort the
     /// imports by module, independent of import style.
     pub force_sort_within_sections: Option<bool>,
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            from-first = true
+        "#
+    )]
+    /// Sort `from` imports (like `from itertools import groupby`) before
+    /// straight imports (like `import sys`) within each section.
+    pub from_first: Option<bool>,
     #[option(
         default = r#"false"#,
         value_type = "bool",
@@ -139,6 +209,30 @@ pub struct Options {
     )]
     /// Sort imports taking into account case sensitivity.
     pub case_sensitive: Option<bool>,
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            length-sort = true
+        "#
+    )]
+    /// Sort imports by their string length, such that shorter imports appear
+    /// before longer imports of the same type. Sorting is based on the
+    /// rendered form of the import (including any `as` aliases and, for
+    /// `from` imports, the `from x import` prefix); imports of equal length
+    /// fall back to the existing alphabetical (or type-based) ordering.
+    pub length_sort: Option<bool>,
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            length-sort-straight = true
+        "#
+    )]
+    /// Sort straight imports (like `import sys`) by their string length.
+    /// Unlike `length-sort`, this leaves `from` imports in their existing
+    /// alphabetical order.
+    pub length_sort_straight: Option<bool>,
     #[option(
         default = r#"[]"#,
         value_type = "list[str]",
@@ -216,6 +310,21 @@ pub struct Options {
     /// this to "closest-to-furthest" is equivalent to isort's
     /// `reverse-relative = true`.
     pub relative_imports_order: Option<RelativeImportsOrder>,
+    #[option(
+        default = r#"alphabetical"#,
+        value_type = r#""alphabetical" | "appearance""#,
+        example = r#"
+            sort-order = "appearance"
+        "#
+    )]
+    /// Whether to sort imports within a section alphabetically (the
+    /// default), or to instead preserve the order in which they first
+    /// appear in the source. Appearance order still applies section
+    /// grouping, deduplication, and wrapping; it simply skips the
+    /// alphabetical re-sort, which is useful when migrating a large
+    /// codebase to isort-style grouping without the churn of a full
+    /// alphabetical reorder.
+    pub sort_order: Option<SortOrder>,
     #[option(
         default = r#"[]"#,
         value_type = "list[str]",
@@ -320,19 +429,57 @@ pub struct Options {
     /// sources; however, if `src` is _not_ configured, this heuristic can be useful to detect
     /// first-party imports from _within_ (but not _across_) first-party packages.
     pub detect_same_package: Option<bool>,
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            treat-sections-as-regex = true
+        "#
+    )]
+    /// Treat the module patterns in `sections` (as well as `known-first-party`,
+    /// `known-third-party`, `known-local-folder`, and `extra-standard-library`)
+    /// as regular expressions instead of globs. This matches isort's support
+    /// for regex-based module categorization, which is useful for complex
+    /// monorepo layouts that can't be expressed with glob syntax alone.
+    pub treat_sections_as_regex: Option<bool>,
     // Tables are required to go last.
     #[option(
         default = "{}",
-        value_type = "dict[str, list[str]]",
+        value_type = "dict[str, list[str] | dict[str, list[str] | str]]",
         example = r#"
-            # Group all Django imports into a separate section.
             [tool.ruff.isort.sections]
+            # Group all Django imports into a separate section.
             "django" = ["django"]
+
+            # Keep `first-party` in appearance order, while forcing
+            # `third-party` to be alphabetical, regardless of the global
+            # `sort-order`.
+            "first-party" = { order = "appearance" }
+            "third-party" = { order = "alphabetical" }
+        "#
+    )]
+    /// A list of mappings from section names to modules. By default custom
+    /// sections are output last, but this can be overridden with
+    /// `section-order`.
+    ///
+    /// Each section may also specify its own `order`, overriding the global
+    /// `sort-order` for that section only. This allows, for example, keeping
+    /// `first-party` imports in appearance order while forcing `third-party`
+    /// imports to be strictly alphabetical.
+    pub sections: Option<FxHashMap<ImportSection, SectionConfig>>,
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            [tool.ruff.isort.import-headings]
+            "first-party" = "Local imports"
         "#
     )]
-    /// A list of mappings from section names to modules.
-    /// By default custom sections are output last, but this can be overridden with `section-order`.
-    pub sections: Option<FxHashMap<ImportSection, Vec<String>>>,
+    /// A mapping from section names to comment text to be emitted above each
+    /// section, mirroring isort's `import_heading_*` settings. Each key must
+    /// refer to a section that appears in `section-order`/`sections`;
+    /// unknown sections are ignored with a warning.
+    pub import_headings: Option<FxHashMap<ImportSection, String>>,
 }
 
 #[derive(Debug, CacheKey)]
@@ -342,13 +489,19 @@ pub struct Settings {
     pub combine_as_imports: bool,
     pub force_single_line: bool,
     pub force_sort_within_sections: bool,
+    pub from_first: bool,
     pub case_sensitive: bool,
     pub force_wrap_aliases: bool,
+    pub force_grid_wrap: usize,
+    pub length_sort: bool,
+    pub length_sort_straight: bool,
     pub force_to_top: BTreeSet<String>,
     pub known_modules: KnownModules,
+    pub treat_sections_as_regex: bool,
     pub detect_same_package: bool,
     pub order_by_type: bool,
     pub relative_imports_order: RelativeImportsOrder,
+    pub sort_order: SortOrder,
     pub single_line_exclusions: BTreeSet<String>,
     pub split_on_trailing_comma: bool,
     pub classes: BTreeSet<String>,
@@ -359,6 +512,11 @@ pub struct Settings {
     pub lines_between_types: usize,
     pub forced_separate: Vec<String>,
     pub section_order: Vec<ImportSection>,
+    pub import_headings: FxHashMap<ImportSection, String>,
+    /// Per-section overrides of `sort_order`, keyed by section (built-in or
+    /// user-defined). A section with no entry here falls back to
+    /// `sort_order`.
+    pub section_sort_orders: FxHashMap<ImportSection, SortOrder>,
 }
 
 impl Default for Settings {
@@ -368,13 +526,19 @@ impl Default for Settings {
             combine_as_imports: false,
             force_single_line: false,
             force_sort_within_sections: false,
+            from_first: false,
             detect_same_package: true,
             case_sensitive: false,
             force_wrap_aliases: false,
+            force_grid_wrap: 0,
+            length_sort: false,
+            length_sort_straight: false,
             force_to_top: BTreeSet::new(),
             known_modules: KnownModules::default(),
+            treat_sections_as_regex: false,
             order_by_type: true,
             relative_imports_order: RelativeImportsOrder::default(),
+            sort_order: SortOrder::default(),
             single_line_exclusions: BTreeSet::new(),
             split_on_trailing_comma: true,
             classes: BTreeSet::new(),
@@ -385,6 +549,8 @@ impl Default for Settings {
             lines_between_types: 0,
             forced_separate: Vec::new(),
             section_order: ImportType::iter().map(ImportSection::Known).collect(),
+            import_headings: FxHashMap::default(),
+            section_sort_orders: FxHashMap::default(),
         }
     }
 }
@@ -397,69 +563,90 @@ impl TryFrom<Options> for Settings {
         let mut section_order: Vec<_> = options
             .section_order
             .unwrap_or_else(|| ImportType::iter().map(ImportSection::Known).collect());
-        let known_first_party = options
-            .known_first_party
-            .map(|names| {
-                names
-                    .into_iter()
-                    .map(|name| IdentifierPattern::new(&name))
-                    .collect()
-            })
-            .transpose()
-            .map_err(SettingsError::InvalidKnownFirstParty)?
-            .unwrap_or_default();
-        let known_third_party = options
-            .known_third_party
-            .map(|names| {
-                names
-                    .into_iter()
-                    .map(|name| IdentifierPattern::new(&name))
-                    .collect()
-            })
-            .transpose()
-            .map_err(SettingsError::InvalidKnownThirdParty)?
-            .unwrap_or_default();
-        let known_local_folder = options
-            .known_local_folder
-            .map(|names| {
-                names
-                    .into_iter()
-                    .map(|name| IdentifierPattern::new(&name))
-                    .collect()
-            })
-            .transpose()
-            .map_err(SettingsError::InvalidKnownLocalFolder)?
-            .unwrap_or_default();
-        let extra_standard_library = options
-            .extra_standard_library
-            .map(|names| {
-                names
-                    .into_iter()
-                    .map(|name| IdentifierPattern::new(&name))
-                    .collect()
-            })
-            .transpose()
-            .map_err(SettingsError::InvalidExtraStandardLibrary)?
-            .unwrap_or_default();
+        let treat_sections_as_regex = options.treat_sections_as_regex.unwrap_or(false);
+
+        // Compile a list of user-provided module-name patterns, as either globs (the default)
+        // or regular expressions (when `treat-sections-as-regex` is set), surfacing a
+        // field-specific error - attributed to the right option - on failure.
+        let compile_patterns = |names: Option<Vec<String>>,
+                                 to_error: fn(PatternError) -> SettingsError|
+         -> Result<Vec<ModulePattern>, SettingsError> {
+            names
+                .unwrap_or_default()
+                .iter()
+                .map(|name| {
+                    if treat_sections_as_regex {
+                        ModulePattern::regex(name).map_err(PatternError::Regex)
+                    } else {
+                        ModulePattern::glob(name).map_err(PatternError::Glob)
+                    }
+                    .map_err(to_error)
+                })
+                .collect()
+        };
+
+        let known_first_party = compile_patterns(
+            options.known_first_party,
+            SettingsError::InvalidKnownFirstParty,
+        )?;
+        let known_third_party = compile_patterns(
+            options.known_third_party,
+            SettingsError::InvalidKnownThirdParty,
+        )?;
+        let known_local_folder = compile_patterns(
+            options.known_local_folder,
+            SettingsError::InvalidKnownLocalFolder,
+        )?;
+        let extra_standard_library = compile_patterns(
+            options.extra_standard_library,
+            SettingsError::InvalidExtraStandardLibrary,
+        )?;
         let no_lines_before = options.no_lines_before.unwrap_or_default();
         let sections = options.sections.unwrap_or_default();
+        let import_headings = options.import_headings.unwrap_or_default();
 
-        // Verify that `sections` doesn't contain any built-in sections.
-        let sections: FxHashMap<String, Vec<glob::Pattern>> = sections
+        // Extract any per-section ordering overrides first, before built-in sections are
+        // dropped below - a built-in section (e.g. `first-party`) carries its module
+        // membership via `known-first-party` et al., but can still override the global
+        // `sort-order` here, just like a user-defined section can.
+        let section_sort_orders: FxHashMap<ImportSection, SortOrder> = sections
+            .iter()
+            .filter_map(|(section, config)| Some((section.clone(), config.order()?)))
+            .collect();
+
+        // Verify that `sections` doesn't contain any built-in sections, and warn if one
+        // specifies a non-empty module list - built-in sections get their membership from
+        // `known-first-party` et al., not from `sections`, so only their `order` is honored.
+        let sections: FxHashMap<String, SectionConfig> = sections
             .into_iter()
-            .filter_map(|(section, modules)| match section {
+            .filter_map(|(section, config)| match section {
                 ImportSection::Known(section) => {
-                    warn_user_once!("`sections` contains built-in section: `{:?}`", section);
+                    if !config.modules().is_empty() {
+                        warn_user_once!(
+                            "`sections` contains built-in section: `{:?}`; \
+                                only its `order` is honored, `modules` is ignored",
+                            section
+                        );
+                    }
                     None
                 }
-                ImportSection::UserDefined(section) => Some((section, modules)),
+                ImportSection::UserDefined(section) => Some((section, config)),
             })
-            .map(|(section, modules)| {
-                let modules = modules
-                    .into_iter()
+            .collect();
+
+        let sections: FxHashMap<String, Vec<ModulePattern>> = sections
+            .into_iter()
+            .map(|(section, config)| {
+                let modules = config
+                    .modules()
+                    .iter()
                     .map(|module| {
-                        IdentifierPattern::new(&module)
-                            .map_err(SettingsError::InvalidUserDefinedSection)
+                        if treat_sections_as_regex {
+                            ModulePattern::regex(module).map_err(PatternError::Regex)
+                        } else {
+                            ModulePattern::glob(module).map_err(PatternError::Glob)
+                        }
+                        .map_err(SettingsError::InvalidUserDefinedSection)
                     })
                     .collect::<Result<Vec<_>, Self::Error>>()?;
                 Ok((section, modules))
@@ -499,6 +686,16 @@ impl TryFrom<Options> for Settings {
             }
         }
 
+        // Verify that all sections listed in `import_headings` are defined in `section_order`.
+        for section in import_headings.keys() {
+            if !section_order.contains(section) {
+                warn_user_once!(
+                    "`import-headings` contains unknown section: `{:?}`",
+                    section,
+                );
+            }
+        }
+
         // Add all built-in sections to `section_order`, if not already present.
         for section in ImportType::iter().map(ImportSection::Known) {
             if !section_order.contains(&section) {
@@ -524,8 +721,12 @@ impl TryFrom<Options> for Settings {
             combine_as_imports: options.combine_as_imports.unwrap_or(false),
             force_single_line: options.force_single_line.unwrap_or(false),
             force_sort_within_sections: options.force_sort_within_sections.unwrap_or(false),
+            from_first: options.from_first.unwrap_or(false),
             case_sensitive: options.case_sensitive.unwrap_or(false),
             force_wrap_aliases: options.force_wrap_aliases.unwrap_or(false),
+            force_grid_wrap: options.force_grid_wrap.unwrap_or_default(),
+            length_sort: options.length_sort.unwrap_or(false),
+            length_sort_straight: options.length_sort_straight.unwrap_or(false),
             detect_same_package: options.detect_same_package.unwrap_or(true),
             force_to_top: BTreeSet::from_iter(options.force_to_top.unwrap_or_default()),
             known_modules: KnownModules::new(
@@ -535,8 +736,10 @@ impl TryFrom<Options> for Settings {
                 extra_standard_library,
                 sections,
             ),
+            treat_sections_as_regex,
             order_by_type: options.order_by_type.unwrap_or(true),
             relative_imports_order: options.relative_imports_order.unwrap_or_default(),
+            sort_order: options.sort_order.unwrap_or_default(),
             single_line_exclusions: BTreeSet::from_iter(
                 options.single_line_exclusions.unwrap_or_default(),
             ),
@@ -549,18 +752,46 @@ impl TryFrom<Options> for Settings {
             lines_between_types: options.lines_between_types.unwrap_or_default(),
             forced_separate: Vec::from_iter(options.forced_separate.unwrap_or_default()),
             section_order,
+            import_headings,
+            section_sort_orders,
         })
     }
 }
 
+/// Either a `glob::Pattern` or `regex::Regex` compilation failure, depending on whether
+/// `treat-sections-as-regex` is enabled for the pattern in question.
+#[derive(Debug)]
+pub enum PatternError {
+    Glob(glob::PatternError),
+    Regex(regex::Error),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::Glob(err) => write!(f, "{err}"),
+            PatternError::Regex(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for PatternError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PatternError::Glob(err) => Some(err),
+            PatternError::Regex(err) => Some(err),
+        }
+    }
+}
+
 /// Error returned by the [`TryFrom`] implementation of [`Settings`].
 #[derive(Debug)]
 pub enum SettingsError {
-    InvalidKnownFirstParty(glob::PatternError),
-    InvalidKnownThirdParty(glob::PatternError),
-    InvalidKnownLocalFolder(glob::PatternError),
-    InvalidExtraStandardLibrary(glob::PatternError),
-    InvalidUserDefinedSection(glob::PatternError),
+    InvalidKnownFirstParty(PatternError),
+    InvalidKnownThirdParty(PatternError),
+    InvalidKnownLocalFolder(PatternError),
+    InvalidExtraStandardLibrary(PatternError),
+    InvalidUserDefinedSection(PatternError),
 }
 
 impl fmt::Display for SettingsError {
@@ -611,9 +842,14 @@ impl From<Settings> for Options {
             ),
             force_single_line: Some(settings.force_single_line),
             force_sort_within_sections: Some(settings.force_sort_within_sections),
+            from_first: Some(settings.from_first),
             case_sensitive: Some(settings.case_sensitive),
             force_wrap_aliases: Some(settings.force_wrap_aliases),
+            force_grid_wrap: Some(settings.force_grid_wrap),
+            length_sort: Some(settings.length_sort),
+            length_sort_straight: Some(settings.length_sort_straight),
             detect_same_package: Some(settings.detect_same_package),
+            treat_sections_as_regex: Some(settings.treat_sections_as_regex),
             force_to_top: Some(settings.force_to_top.into_iter().collect()),
             known_first_party: Some(
                 settings
@@ -638,6 +874,7 @@ impl From<Settings> for Options {
             ),
             order_by_type: Some(settings.order_by_type),
             relative_imports_order: Some(settings.relative_imports_order),
+            sort_order: Some(settings.sort_order),
             single_line_exclusions: Some(settings.single_line_exclusions.into_iter().collect()),
             split_on_trailing_comma: Some(settings.split_on_trailing_comma),
             classes: Some(settings.classes.into_iter().collect()),
@@ -654,13 +891,36 @@ impl From<Settings> for Options {
                     .user_defined()
                     .into_iter()
                     .map(|(section, modules)| {
-                        (
-                            ImportSection::UserDefined(section.to_string()),
-                            modules.into_iter().map(ToString::to_string).collect(),
-                        )
+                        let section = ImportSection::UserDefined(section.to_string());
+                        let modules: Vec<String> =
+                            modules.into_iter().map(ToString::to_string).collect();
+                        let config = match settings.section_sort_orders.get(&section) {
+                            Some(&order) => SectionConfig::WithOrder {
+                                modules,
+                                order: Some(order),
+                            },
+                            None => SectionConfig::Modules(modules),
+                        };
+                        (section, config)
                     })
+                    // Built-in sections never carry `modules` (that comes from
+                    // `known-first-party` et al.), but they can still carry a per-section
+                    // `order` override.
+                    .chain(settings.section_sort_orders.iter().filter_map(
+                        |(section, &order)| match section {
+                            ImportSection::Known(_) => Some((
+                                section.clone(),
+                                SectionConfig::WithOrder {
+                                    modules: Vec::new(),
+                                    order: Some(order),
+                                },
+                            )),
+                            ImportSection::UserDefined(_) => None,
+                        },
+                    ))
                     .collect(),
             ),
+            import_headings: Some(settings.import_headings.into_iter().collect()),
         }
     }
 }
@@ -0,0 +1,314 @@
+//! Ordering of imports within a single section.
+
+use std::cmp::Ordering;
+
+use super::categorize::ImportSection;
+use super::settings::{Settings, SortOrder};
+
+/// An import, reduced to the fields [`cmp_imports`] needs to rank it against
+/// its neighbors within the same section.
+#[derive(Debug, Clone, Copy)]
+pub struct ImportKey<'a> {
+    /// The module being imported (e.g. `itertools` for `from itertools import groupby`),
+    /// used to break ties and as the default ordering key.
+    pub module_name: &'a str,
+    /// `true` for a "straight" import (`import sys`); `false` for a `from` import
+    /// (`from itertools import groupby`).
+    pub is_straight_import: bool,
+    /// The fully rendered import line (including any `as` alias and, for `from`
+    /// imports, the `from x import` prefix), used by `length-sort`/`length-sort-straight`.
+    pub rendered: &'a str,
+    /// The 0-based position at which this import first appears in the source, used
+    /// when the section's effective `sort-order` is [`SortOrder::Appearance`].
+    pub first_seen_index: usize,
+}
+
+/// Order two imports within the same `section`, honoring the section's effective
+/// `sort-order` (falling back to the global `sort-order` when `section` has no
+/// override in `settings.section_sort_orders`), `from-first` (placing `from` imports
+/// before straight imports), and `length-sort`/`length-sort-straight`.
+pub fn cmp_imports(
+    left: &ImportKey,
+    right: &ImportKey,
+    section: &ImportSection,
+    settings: &Settings,
+) -> Ordering {
+    if settings.from_first {
+        // `from` imports (`is_straight_import: false`) sort before straight imports.
+        match left.is_straight_import.cmp(&right.is_straight_import) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+    }
+    if section_sort_order(section, settings) == SortOrder::Appearance {
+        return left.first_seen_index.cmp(&right.first_seen_index);
+    }
+    if length_sort_applies(left, settings) && length_sort_applies(right, settings) {
+        return left
+            .rendered
+            .len()
+            .cmp(&right.rendered.len())
+            .then_with(|| compare_names(left.module_name, right.module_name, settings));
+    }
+    compare_names(left.module_name, right.module_name, settings)
+}
+
+/// The existing alphabetical/type-based comparator used as the default ordering, and as
+/// the tie-break for `length-sort`: honors `order-by-type` (grouping constants, then
+/// classes, then other names, per [`name_type`]) and `case-sensitive` (falling back to a
+/// case-insensitive comparison, per isort's documented default, with the original strings
+/// as a tie-break to keep the ordering total).
+fn compare_names(left: &str, right: &str, settings: &Settings) -> Ordering {
+    if settings.order_by_type {
+        match name_type(left, settings).cmp(&name_type(right, settings)) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+    }
+    if settings.case_sensitive {
+        left.cmp(right)
+    } else {
+        left.to_lowercase()
+            .cmp(&right.to_lowercase())
+            .then_with(|| left.cmp(right))
+    }
+}
+
+/// The "type" of a name for `order-by-type` purposes: a `CONSTANT`, a `Class`, or a
+/// `variable`, in that sort-precedence order. Honors the `constants`/`classes`/`variables`
+/// override lists before falling back to a casing heuristic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum NameType {
+    Constant,
+    Class,
+    Variable,
+}
+
+fn name_type(name: &str, settings: &Settings) -> NameType {
+    if settings.constants.contains(name) {
+        return NameType::Constant;
+    }
+    if settings.classes.contains(name) {
+        return NameType::Class;
+    }
+    if settings.variables.contains(name) {
+        return NameType::Variable;
+    }
+    match name.chars().next() {
+        Some(c) if c.is_uppercase() => {
+            if name.chars().any(char::is_lowercase) {
+                NameType::Class
+            } else {
+                NameType::Constant
+            }
+        }
+        _ => NameType::Variable,
+    }
+}
+
+/// The effective `sort-order` for `section`: its own override from
+/// `settings.section_sort_orders`, if any, otherwise the global `settings.sort_order`.
+fn section_sort_order(section: &ImportSection, settings: &Settings) -> SortOrder {
+    settings
+        .section_sort_orders
+        .get(section)
+        .copied()
+        .unwrap_or(settings.sort_order)
+}
+
+/// Returns `true` if `length-sort` (or `length-sort-straight`, for a straight import)
+/// applies to this import.
+fn length_sort_applies(key: &ImportKey, settings: &Settings) -> bool {
+    settings.length_sort || (settings.length_sort_straight && key.is_straight_import)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::isort::ImportType;
+
+    const SECTION: ImportSection = ImportSection::Known(ImportType::StandardLibrary);
+
+    fn key(module_name: &str, is_straight_import: bool, rendered: &str) -> ImportKey {
+        ImportKey {
+            module_name,
+            is_straight_import,
+            rendered,
+            first_seen_index: 0,
+        }
+    }
+
+    fn settings(length_sort: bool, length_sort_straight: bool) -> Settings {
+        Settings {
+            length_sort,
+            length_sort_straight,
+            ..Settings::default()
+        }
+    }
+
+    #[test]
+    fn from_first_places_from_imports_before_straight_imports() {
+        let straight = key("a", true, "import a");
+        let from = key("z", false, "from z import z");
+        let settings = Settings {
+            from_first: true,
+            ..Settings::default()
+        };
+        assert_eq!(
+            cmp_imports(&from, &straight, &SECTION, &settings),
+            Ordering::Less
+        );
+        assert_eq!(
+            cmp_imports(&straight, &from, &SECTION, &settings),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn length_sort_orders_shorter_imports_first() {
+        let short = key("os", true, "import os");
+        let long = key("itertools", true, "import itertools");
+        assert_eq!(
+            cmp_imports(&short, &long, &SECTION, &settings(true, false)),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn length_sort_straight_ignores_from_imports() {
+        let short_from = key("z", false, "from z import a");
+        let long_from = key("a", false, "from a import bbbbbbbbbb");
+        // `length-sort-straight` doesn't apply to `from` imports, so they fall back to the
+        // default alphabetical ordering (`a` before `z`).
+        assert_eq!(
+            cmp_imports(&short_from, &long_from, &SECTION, &settings(false, true)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn without_length_sort_falls_back_to_alphabetical() {
+        let a = key("a", true, "import a");
+        let b = key("bbbbbbbbbb", true, "import bbbbbbbbbb");
+        assert_eq!(
+            cmp_imports(&a, &b, &SECTION, &settings(false, false)),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn appearance_order_ignores_alphabetical_and_length_sort() {
+        let first = ImportKey {
+            module_name: "zzz",
+            is_straight_import: true,
+            rendered: "import zzz",
+            first_seen_index: 0,
+        };
+        let second = ImportKey {
+            module_name: "a",
+            is_straight_import: true,
+            rendered: "import a",
+            first_seen_index: 1,
+        };
+        let settings = Settings {
+            sort_order: SortOrder::Appearance,
+            length_sort: true,
+            ..Settings::default()
+        };
+        assert_eq!(
+            cmp_imports(&first, &second, &SECTION, &settings),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn case_insensitive_by_default() {
+        // With the default `case-sensitive = false`, casing shouldn't affect ordering:
+        // "abc" sorts before "Zope" alphabetically, not after it as a byte-wise
+        // comparison would produce (uppercase `Z` < lowercase `a` in ASCII). Disable
+        // `order-by-type` so casing-derived type grouping doesn't dominate the result.
+        let lower = key("abc", true, "import abc");
+        let upper = key("Zope", true, "import Zope");
+        let settings = Settings {
+            order_by_type: false,
+            ..Settings::default()
+        };
+        assert_eq!(
+            cmp_imports(&lower, &upper, &SECTION, &settings),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn case_sensitive_uses_byte_wise_ordering() {
+        let lower = key("abc", true, "import abc");
+        let upper = key("Zope", true, "import Zope");
+        let settings = Settings {
+            case_sensitive: true,
+            order_by_type: false,
+            ..Settings::default()
+        };
+        assert_eq!(
+            cmp_imports(&lower, &upper, &SECTION, &settings),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn order_by_type_groups_constants_before_classes_before_variables() {
+        let constant = key("CONST", true, "import CONST");
+        let class = key("Class", true, "import Class");
+        let variable = key("variable", true, "import variable");
+        let settings = Settings::default();
+        assert_eq!(
+            cmp_imports(&constant, &class, &SECTION, &settings),
+            Ordering::Less
+        );
+        assert_eq!(
+            cmp_imports(&class, &variable, &SECTION, &settings),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn order_by_type_honors_variables_override() {
+        // `Utils` looks like a Class by casing, but is configured as a plain variable.
+        let overridden = key("Utils", true, "import Utils");
+        let class = key("Other", true, "import Other");
+        let mut settings = Settings::default();
+        settings.variables.insert("Utils".to_string());
+        assert_eq!(
+            cmp_imports(&overridden, &class, &SECTION, &settings),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn per_section_order_override_takes_priority_over_global_sort_order() {
+        let first = ImportKey {
+            module_name: "zzz",
+            is_straight_import: true,
+            rendered: "import zzz",
+            first_seen_index: 0,
+        };
+        let second = ImportKey {
+            module_name: "a",
+            is_straight_import: true,
+            rendered: "import a",
+            first_seen_index: 1,
+        };
+        let mut settings = Settings {
+            sort_order: SortOrder::Appearance,
+            ..Settings::default()
+        };
+        settings
+            .section_sort_orders
+            .insert(SECTION, SortOrder::Alphabetical);
+        // The section override forces alphabetical ordering, even though the global
+        // `sort-order` is `appearance`.
+        assert_eq!(
+            cmp_imports(&first, &second, &SECTION, &settings),
+            Ordering::Greater
+        );
+    }
+}
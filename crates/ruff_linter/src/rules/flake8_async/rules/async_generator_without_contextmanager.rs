@@ -49,7 +49,65 @@ impl crate::Violation for AsyncGeneratorWithoutContextmanager {
     }
 }
 
-/// ASYNC900
+/// ## What it does
+/// Checks for async generators that yield while a resource-cleanup scope
+/// (`try`/`finally`, `with`, or `async with`) is open, and that are not
+/// decorated with `@asynccontextmanager` or other configured safe decorators.
+///
+/// ## Why is this bad?
+/// If an async generator is abandoned (e.g. its consumer stops iterating
+/// early, or the generator is garbage-collected) while a `yield` sits inside
+/// a cleanup scope, the cleanup code after the `yield` may never run, or may
+/// run at an unpredictable time. This is the PEP 533 "delayed cleanup"
+/// hazard, and it is strictly more dangerous than the broader (lower-
+/// confidence) pattern flagged by `AsyncGeneratorWithoutContextmanager`:
+/// every case this rule flags is also flagged by that one, so enable this
+/// rule on its own to focus on the genuinely risky subset without the noise
+/// of flagging every bare async generator.
+///
+/// ## Example
+/// ```python
+/// async def get_data():
+///     resource = acquire_resource()
+///     try:
+///         yield resource
+///     finally:
+///         release_resource(resource)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from contextlib import asynccontextmanager
+///
+/// @asynccontextmanager
+/// async def get_data():
+///     resource = acquire_resource()
+///     try:
+///         yield resource
+///     finally:
+///         release_resource(resource)
+/// ```
+///
+/// ## Options
+/// - `flake8-async.transform-async-generator-decorators`: A list of additional
+///   decorators that should be treated as safe for async generators (e.g.,
+///   `["trio_util.trio_async_generator"]`).
+///
+/// [PEP 533]: https://peps.python.org/pep-0533/
+/// [PEP 789]: https://peps.python.org/pep-0789/
+#[derive(ViolationMetadata)]
+pub(crate) struct AsyncGeneratorCleanupScopeYield;
+
+impl crate::Violation for AsyncGeneratorCleanupScopeYield {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Async generator yields while a resource is held open; wrap it with \
+            `@asynccontextmanager` to guarantee cleanup runs"
+            .to_string()
+    }
+}
+
+/// ASYNC900, ASYNC902
 pub(crate) fn async_generator_without_contextmanager(
     checker: &Checker,
     function_def: &ast::StmtFunctionDef,
@@ -59,8 +117,10 @@ pub(crate) fn async_generator_without_contextmanager(
         return;
     }
 
-    // Check if the function is an async generator (contains yield)
-    if !is_async_generator(function_def) {
+    // Check if the function is an async generator (contains yield), and whether any of its
+    // yields occur inside a cleanup scope (a `try`/`finally`, `with`, or `async with` block).
+    let analysis = analyze_generator(function_def);
+    if !analysis.has_yield {
         return;
     }
 
@@ -69,37 +129,67 @@ pub(crate) fn async_generator_without_contextmanager(
         return;
     }
 
-    // Report the violation
-    checker.report_diagnostic(
-        AsyncGeneratorWithoutContextmanager,
-        function_def.name.range(),
-    );
+    // ASYNC900: any undecorated async generator.
+    checker.report_diagnostic(AsyncGeneratorWithoutContextmanager, function_def.name.range());
+
+    // ASYNC902: the narrower, higher-confidence PEP 533 delayed-cleanup hazard. Selectable
+    // independently of ASYNC900.
+    if analysis.has_cleanup_scope_yield {
+        checker.report_diagnostic(AsyncGeneratorCleanupScopeYield, function_def.name.range());
+    }
+}
+
+/// The result of walking an async function's body for `yield` statements.
+#[derive(Default)]
+struct GeneratorAnalysis {
+    /// Whether the function contains any `yield` or `yield from`.
+    has_yield: bool,
+    /// Whether any `yield`/`yield from` is lexically nested inside a `try`/`finally`, `with`, or
+    /// `async with` block, i.e. the PEP 533 "delayed cleanup" hazard.
+    has_cleanup_scope_yield: bool,
 }
 
-/// Check if a function is an async generator (contains yield statements)
-fn is_async_generator(function_def: &ast::StmtFunctionDef) -> bool {
+/// Walk a function body for `yield` statements, and determine whether any of them occur while a
+/// cleanup scope (`try`/`finally`, `with`, `async with`) is open.
+fn analyze_generator(function_def: &ast::StmtFunctionDef) -> GeneratorAnalysis {
     use ruff_python_ast::visitor::Visitor;
     use ruff_python_ast::{self as ast};
-    
+
     struct YieldVisitor {
-        has_yield: bool,
+        cleanup_depth: usize,
+        analysis: GeneratorAnalysis,
     }
-    
+
     impl<'a> Visitor<'a> for YieldVisitor {
         fn visit_stmt(&mut self, stmt: &'a ast::Stmt) {
-            // Don't descend into nested functions
             match stmt {
-                ast::Stmt::FunctionDef(_) => return,
-                _ => {
+                // Don't descend into nested functions.
+                ast::Stmt::FunctionDef(_) => {}
+                // A bare `try`/`except` (no `finally`) doesn't delay cleanup: if the
+                // generator is abandoned mid-`yield`, there's no `finally` block whose
+                // execution could be skipped. Only `finally` (and `with`/`async with`,
+                // whose `__exit__` runs on abandonment too) are cleanup scopes.
+                ast::Stmt::Try(try_stmt) if !try_stmt.finalbody.is_empty() => {
+                    self.cleanup_depth += 1;
+                    ruff_python_ast::visitor::walk_stmt(self, stmt);
+                    self.cleanup_depth -= 1;
+                }
+                ast::Stmt::With(_) => {
+                    self.cleanup_depth += 1;
                     ruff_python_ast::visitor::walk_stmt(self, stmt);
+                    self.cleanup_depth -= 1;
                 }
+                _ => ruff_python_ast::visitor::walk_stmt(self, stmt),
             }
         }
-        
+
         fn visit_expr(&mut self, expr: &'a ast::Expr) {
             match expr {
                 ast::Expr::Yield(_) | ast::Expr::YieldFrom(_) => {
-                    self.has_yield = true;
+                    self.analysis.has_yield = true;
+                    if self.cleanup_depth > 0 {
+                        self.analysis.has_cleanup_scope_yield = true;
+                    }
                 }
                 _ => {
                     ruff_python_ast::visitor::walk_expr(self, expr);
@@ -107,24 +197,33 @@ fn is_async_generator(function_def: &ast::StmtFunctionDef) -> bool {
             }
         }
     }
-    
-    let mut visitor = YieldVisitor { has_yield: false };
+
+    let mut visitor = YieldVisitor {
+        cleanup_depth: 0,
+        analysis: GeneratorAnalysis::default(),
+    };
     for stmt in &function_def.body {
         visitor.visit_stmt(stmt);
-        if visitor.has_yield {
-            return true;
-        }
     }
-    false
+    visitor.analysis
 }
 
 /// Check if a function has a safe decorator for async generators
 fn has_safe_decorator(function_def: &ast::StmtFunctionDef, checker: &Checker) -> bool {
-    // Default safe decorators
-    let safe_decorators = vec![
+    // Default safe decorators, plus any user-configured via
+    // `flake8-async.transform-async-generator-decorators`.
+    let mut safe_decorators = vec![
         vec!["contextlib", "asynccontextmanager"],
         vec!["pytest", "fixture"],
     ];
+    let user_decorators: Vec<Vec<&str>> = checker
+        .settings()
+        .flake8_async
+        .transform_async_generator_decorators
+        .iter()
+        .map(|decorator| decorator.split('.').collect())
+        .collect();
+    safe_decorators.extend(user_decorators);
 
     for decorator in &function_def.decorator_list {
         if is_safe_decorator(decorator, &safe_decorators, checker) {
@@ -146,7 +245,7 @@ fn is_safe_decorator(
         ast::Expr::Call(call) => &*call.func,
         other => other,
     };
-    
+
     let Some(qualified_name) = checker
         .semantic()
         .resolve_qualified_name(base_expr)
@@ -161,4 +260,67 @@ fn is_safe_decorator(
     }
 
     false
+}
+
+#[cfg(test)]
+mod tests {
+    use ruff_python_parser::parse_module;
+
+    use super::{analyze_generator, ast};
+
+    fn function_def(source: &str) -> ast::StmtFunctionDef {
+        let parsed = parse_module(source).expect("source should parse");
+        let [stmt]: [ast::Stmt; 1] = parsed
+            .into_syntax()
+            .body
+            .try_into()
+            .ok()
+            .expect("expected exactly one statement");
+        match stmt {
+            ast::Stmt::FunctionDef(function_def) => function_def,
+            other => panic!("expected a function definition, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn bare_yield_is_not_a_cleanup_scope_yield() {
+        let analysis = analyze_generator(&function_def("async def f():\n    yield 1\n"));
+        assert!(analysis.has_yield);
+        assert!(!analysis.has_cleanup_scope_yield);
+    }
+
+    #[test]
+    fn yield_inside_bare_try_except_is_not_a_cleanup_scope_yield() {
+        let analysis = analyze_generator(&function_def(
+            "async def f():\n    try:\n        yield 1\n    except ValueError:\n        pass\n",
+        ));
+        assert!(analysis.has_yield);
+        assert!(!analysis.has_cleanup_scope_yield);
+    }
+
+    #[test]
+    fn yield_inside_try_finally_is_a_cleanup_scope_yield() {
+        let analysis = analyze_generator(&function_def(
+            "async def f():\n    try:\n        yield 1\n    finally:\n        pass\n",
+        ));
+        assert!(analysis.has_yield);
+        assert!(analysis.has_cleanup_scope_yield);
+    }
+
+    #[test]
+    fn yield_inside_with_is_a_cleanup_scope_yield() {
+        let analysis = analyze_generator(&function_def(
+            "async def f():\n    with open('f') as fh:\n        yield fh\n",
+        ));
+        assert!(analysis.has_cleanup_scope_yield);
+    }
+
+    #[test]
+    fn yield_inside_nested_function_is_not_counted() {
+        let analysis = analyze_generator(&function_def(
+            "async def f():\n    def g():\n        yield 1\n    return g\n",
+        ));
+        assert!(!analysis.has_yield);
+        assert!(!analysis.has_cleanup_scope_yield);
+    }
 }
\ No newline at end of file
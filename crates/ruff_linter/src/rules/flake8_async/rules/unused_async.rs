@@ -0,0 +1,280 @@
+use ruff_diagnostics::{Edit, Fix};
+use ruff_macros::{ViolationMetadata, derive_message_formats};
+use ruff_python_ast::visitor::Visitor;
+use ruff_python_ast::{self as ast, Decorator};
+use ruff_python_semantic::ScopeKind;
+use ruff_text_size::{Ranged, TextSize};
+
+use crate::Violation;
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `async` functions that never `await` anything.
+///
+/// ## Why is this bad?
+/// Marking a function `async` imposes coroutine-scheduling overhead on every
+/// caller, without providing any benefit, if the function never actually
+/// suspends (via `await`, `async with`, or `async for`). In most cases, this
+/// indicates that the `async` keyword was added by mistake, and the function
+/// should be a regular, synchronous function instead.
+///
+/// ## Example
+/// ```python
+/// async def fetch_cached(key):
+///     return cache[key]
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def fetch_cached(key):
+///     return cache[key]
+/// ```
+///
+/// ## Known problems
+/// This rule exempts stub bodies (a docstring followed by `...`, `pass`, or
+/// `raise NotImplementedError`), `@abstractmethod`-decorated functions, and
+/// methods on `typing.Protocol` subclasses, all of which may legitimately
+/// stay `async` to satisfy an interface even though the stub itself never
+/// awaits anything.
+///
+/// ## Fix safety
+/// This rule's fix is unsafe. Removing `async` changes the function's type
+/// (e.g., from a coroutine function to a plain function), which can break
+/// callers that `await` the result or otherwise rely on it being a coroutine
+/// function.
+#[derive(ViolationMetadata)]
+pub(crate) struct UnusedAsync;
+
+impl Violation for UnusedAsync {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Async function without any `await` expressions".to_string()
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Remove `async` keyword".to_string())
+    }
+}
+
+/// ASYNC901
+pub(crate) fn unused_async(checker: &Checker, function_def: &ast::StmtFunctionDef) {
+    if !function_def.is_async {
+        return;
+    }
+
+    let mut visitor = AwaitVisitor::default();
+    for stmt in &function_def.body {
+        visitor.visit_stmt(stmt);
+    }
+
+    // Async generators are a distinct (and separately-linted) concern; leave them alone.
+    if visitor.has_yield || visitor.has_await {
+        return;
+    }
+
+    if is_stub_body(function_def) {
+        return;
+    }
+
+    if is_abstract_method(function_def, checker) {
+        return;
+    }
+
+    if is_protocol_method(checker) {
+        return;
+    }
+
+    let mut diagnostic = checker.report_diagnostic(UnusedAsync, function_def.name.range());
+    diagnostic.set_fix(Fix::unsafe_edit(Edit::deletion(
+        function_def.start(),
+        function_def.start() + TextSize::from(6), // `async `
+    )));
+}
+
+/// Records whether an `async def`'s own body (not counting nested scopes)
+/// contains an `await`, `async with`, `async for`, or `yield`.
+#[derive(Default)]
+struct AwaitVisitor {
+    has_await: bool,
+    has_yield: bool,
+}
+
+impl<'a> Visitor<'a> for AwaitVisitor {
+    fn visit_stmt(&mut self, stmt: &'a ast::Stmt) {
+        match stmt {
+            // Don't descend into nested scopes: an `await` in a nested function or
+            // lambda doesn't make the outer function itself awaiting.
+            ast::Stmt::FunctionDef(_) => {}
+            ast::Stmt::With(with_stmt) if with_stmt.is_async => {
+                self.has_await = true;
+                ruff_python_ast::visitor::walk_stmt(self, stmt);
+            }
+            ast::Stmt::For(for_stmt) if for_stmt.is_async => {
+                self.has_await = true;
+                ruff_python_ast::visitor::walk_stmt(self, stmt);
+            }
+            _ => ruff_python_ast::visitor::walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'a ast::Expr) {
+        match expr {
+            ast::Expr::Await(_) => self.has_await = true,
+            ast::Expr::Yield(_) | ast::Expr::YieldFrom(_) => self.has_yield = true,
+            // Don't descend into a nested `lambda`: it can't itself contain `await`, `async
+            // with`, or `async for`. Comprehensions (`ListComp`, `SetComp`, `DictComp`,
+            // `Generator`), unlike `lambda` and nested `def`s, share the enclosing coroutine
+            // frame for `await`/`async for` purposes, so they must still be walked.
+            ast::Expr::Lambda(_) => return,
+            _ => {}
+        }
+        ruff_python_ast::visitor::walk_expr(self, expr);
+    }
+
+    fn visit_comprehension(&mut self, comprehension: &'a ast::Comprehension) {
+        // `async for` clauses in a comprehension (e.g. `[x async for x in xs]`) suspend the
+        // enclosing function just like a statement-level `async for`.
+        if comprehension.is_async {
+            self.has_await = true;
+        }
+        ruff_python_ast::visitor::walk_comprehension(self, comprehension);
+    }
+}
+
+/// Returns `true` if the function's body is a stub: an optional docstring
+/// followed by nothing but `...`, `pass`, or `raise NotImplementedError`.
+fn is_stub_body(function_def: &ast::StmtFunctionDef) -> bool {
+    let mut body = function_def.body.iter();
+    if matches!(
+        body.clone().next(),
+        Some(ast::Stmt::Expr(ast::StmtExpr { value, .. })) if value.is_string_literal_expr()
+    ) {
+        body.next();
+    }
+
+    match body.as_slice() {
+        [] | [ast::Stmt::Pass(_)] => true,
+        [ast::Stmt::Expr(ast::StmtExpr { value, .. })] => value.is_ellipsis_literal_expr(),
+        [ast::Stmt::Raise(ast::StmtRaise { exc: Some(exc), .. })] => is_not_implemented_error(exc),
+        _ => false,
+    }
+}
+
+fn is_not_implemented_error(expr: &ast::Expr) -> bool {
+    let callee = match expr {
+        ast::Expr::Call(call) => call.func.as_ref(),
+        other => other,
+    };
+    matches!(callee, ast::Expr::Name(name) if name.id.as_str() == "NotImplementedError")
+}
+
+/// Returns `true` if the function is decorated with `@abstractmethod` (however imported).
+fn is_abstract_method(function_def: &ast::StmtFunctionDef, checker: &Checker) -> bool {
+    function_def
+        .decorator_list
+        .iter()
+        .any(|decorator| is_abstractmethod_decorator(decorator, checker))
+}
+
+fn is_abstractmethod_decorator(decorator: &Decorator, checker: &Checker) -> bool {
+    let Some(qualified_name) = checker.semantic().resolve_qualified_name(&decorator.expression)
+    else {
+        return false;
+    };
+    matches!(
+        qualified_name.segments(),
+        ["abc", "abstractmethod"] | ["abstractmethod"]
+    )
+}
+
+/// Returns `true` if the function is a method defined directly on a class that derives from
+/// `typing.Protocol` (or `typing_extensions.Protocol`).
+fn is_protocol_method(checker: &Checker) -> bool {
+    let ScopeKind::Class(class_def) = &checker.semantic().current_scope().kind else {
+        return false;
+    };
+    class_def.bases().iter().any(|base| {
+        checker
+            .semantic()
+            .resolve_qualified_name(base)
+            .is_some_and(|qualified_name| {
+                matches!(
+                    qualified_name.segments(),
+                    ["typing", "Protocol"] | ["typing_extensions", "Protocol"]
+                )
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ruff_python_ast::visitor::Visitor;
+    use ruff_python_parser::parse_module;
+
+    use super::{ast, is_stub_body, AwaitVisitor};
+
+    fn function_def(source: &str) -> ast::StmtFunctionDef {
+        let parsed = parse_module(source).expect("source should parse");
+        let [stmt]: [ast::Stmt; 1] = parsed
+            .into_syntax()
+            .body
+            .try_into()
+            .ok()
+            .expect("expected exactly one statement");
+        match stmt {
+            ast::Stmt::FunctionDef(function_def) => function_def,
+            other => panic!("expected a function definition, got {other:?}"),
+        }
+    }
+
+    fn has_await(source: &str) -> bool {
+        let function_def = function_def(source);
+        let mut visitor = AwaitVisitor::default();
+        for stmt in &function_def.body {
+            visitor.visit_stmt(stmt);
+        }
+        visitor.has_await
+    }
+
+    #[test]
+    fn detects_await_inside_list_comprehension() {
+        assert!(has_await("async def f():\n    return [await x() for x in xs]\n"));
+    }
+
+    #[test]
+    fn detects_async_for_inside_comprehension() {
+        assert!(has_await("async def f():\n    return [x async for x in xs]\n"));
+    }
+
+    #[test]
+    fn does_not_descend_into_lambda() {
+        assert!(!has_await("async def f():\n    return lambda: (await x())\n"));
+    }
+
+    #[test]
+    fn detects_async_for_statement() {
+        assert!(has_await("async def f():\n    async for x in xs:\n        pass\n"));
+    }
+
+    #[test]
+    fn detects_async_with_statement() {
+        assert!(has_await("async def f():\n    async with x() as y:\n        pass\n"));
+    }
+
+    #[test]
+    fn stub_body_with_ellipsis_is_a_stub() {
+        assert!(is_stub_body(&function_def("async def f():\n    ...\n")));
+    }
+
+    #[test]
+    fn stub_body_with_docstring_and_not_implemented_is_a_stub() {
+        assert!(is_stub_body(&function_def(
+            "async def f():\n    \"\"\"Docs.\"\"\"\n    raise NotImplementedError\n"
+        )));
+    }
+
+    #[test]
+    fn non_stub_body_is_not_a_stub() {
+        assert!(!is_stub_body(&function_def("async def f():\n    return 1\n")));
+    }
+}
@@ -0,0 +1,53 @@
+//! Settings for the `flake8-async` plugin.
+
+use ruff_macros::{CacheKey, CombineOptions, ConfigurationOptions};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, CombineOptions,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "Flake8AsyncOptions"
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Options {
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"
+            transform-async-generator-decorators = ["trio_util.trio_async_generator"]
+        "#
+    )]
+    /// A list of decorators, in addition to the built-in
+    /// `contextlib.asynccontextmanager` and `pytest.fixture`, that should be
+    /// treated as transforming an async generator into a safe context
+    /// manager or fixture, for the purposes of `ASYNC900` and `ASYNC902`.
+    pub transform_async_generator_decorators: Option<Vec<String>>,
+}
+
+#[derive(Debug, CacheKey, Default)]
+pub struct Settings {
+    pub transform_async_generator_decorators: Vec<String>,
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            transform_async_generator_decorators: options
+                .transform_async_generator_decorators
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            transform_async_generator_decorators: Some(
+                settings.transform_async_generator_decorators,
+            ),
+        }
+    }
+}